@@ -133,7 +133,27 @@ impl SpecialChars {
     }
 }
 
-/// Produces escaped (encoded) message surrounded with `FEND`
+/// Default maximum number of payload bytes accepted by [`encode`]/[`decode`], matching
+/// the crate's original fixed 260-byte limit.
+pub const DEFAULT_MAX_PAYLOAD: usize = 260;
+
+/// Default maximum number of raw (stuffed) bytes [`decode`] will accept as input,
+/// matching the crate's original fixed 1000-byte limit.
+pub const DEFAULT_MAX_INPUT: usize = 1000;
+
+/// Default frame buffer capacity used by [`encode`]/[`decode`], matching the crate's
+/// original fixed 1024-byte `ArrayVec` buffers.
+pub const DEFAULT_FRAME: usize = 1024;
+
+/// Produces escaped (encoded) message surrounded with `FEND`.
+///
+/// The output buffer and the accepted payload size are fixed at [`DEFAULT_FRAME`] and
+/// [`DEFAULT_MAX_PAYLOAD`] bytes: `arrayvec`'s `ArrayVec<[T; N]>` only implements its
+/// `Array` trait for a fixed, enumerated list of sizes rather than for any `usize`, so
+/// these capacities can't be made generic over a caller-chosen const without upgrading
+/// the crate's `arrayvec` dependency. Callers on RAM-constrained targets who need a
+/// smaller buffer should use [`encode_slice`] to encode into a caller-provided `&mut [u8]`
+/// instead.
 ///
 /// # Inputs
 /// * **Vec<u8>**: A vector of the bytes you want to encode
@@ -155,15 +175,18 @@ impl SpecialChars {
 /// let input: Vec<u8> = vec![0x01, 0x50, 0x00, 0x00, 0x00, 0x05, 0x80, 0x09];
 /// let op_vec = sensirion_hdlc::encode(&input.to_vec(), chars);
 /// ```
-pub fn encode(data: &[u8], s_chars: SpecialChars) -> Result<ArrayVec<[u8; 1024]>, HDLCError> {
-    if data.len() > 260 {
+pub fn encode(
+    data: &[u8],
+    s_chars: SpecialChars,
+) -> Result<ArrayVec<[u8; DEFAULT_FRAME]>, HDLCError> {
+    if data.len() > DEFAULT_MAX_PAYLOAD {
         return Err(HDLCError::TooMuchData);
     }
 
     // Iterator over the input that allows peeking
     let input_iter = data.iter();
 
-    let mut output = ArrayVec::<[_; 1024]>::new();
+    let mut output = ArrayVec::<[u8; DEFAULT_FRAME]>::new();
     //Push initial FEND
     output.push(s_chars.fend);
 
@@ -200,6 +223,15 @@ pub fn encode(data: &[u8], s_chars: SpecialChars) -> Result<ArrayVec<[u8; 1024]>
 
 /// Produces unescaped (decoded) message without `FEND` characters.
 ///
+/// The output buffer is sized to [`DEFAULT_FRAME`] bytes, well above the
+/// [`DEFAULT_MAX_PAYLOAD`]-byte limit enforced on the decoded result, so an
+/// over-the-limit-but-otherwise-valid frame returns [`HDLCError::TooMuchDecodedData`]
+/// rather than overflowing the buffer. Input is capped at [`DEFAULT_MAX_INPUT`] raw
+/// bytes. As with [`encode`], these capacities are fixed rather than generic: `arrayvec`'s
+/// `ArrayVec<[T; N]>` only implements its `Array` trait for a fixed, enumerated list of
+/// sizes. Callers on RAM-constrained targets who need a smaller buffer should use
+/// [`decode_slice`] to decode into a caller-provided `&mut [u8]` instead.
+///
 /// # Inputs
 /// * **Vec<u8>**: A vector of the bytes you want to decode
 /// * **SpecialChars**: The special characters you want to swap
@@ -228,12 +260,15 @@ pub fn encode(data: &[u8], s_chars: SpecialChars) -> Result<ArrayVec<[u8; 1024]>
 /// let input =[ 0x7E, 0x01, 0x50, 0x00, 0x00, 0x00, 0x05, 0x80, 0x09, 0x7E];
 /// let op_vec = sensirion_hdlc::decode(&input.to_vec(), chars);
 /// ```
-pub fn decode(input: &[u8], s_chars: SpecialChars) -> Result<ArrayVec<[u8; 1024]>, HDLCError> {
+pub fn decode(
+    input: &[u8],
+    s_chars: SpecialChars,
+) -> Result<ArrayVec<[u8; DEFAULT_FRAME]>, HDLCError> {
     if input.len() < 4 {
         return Err(HDLCError::TooFewData);
     }
 
-    if input.len() > 1000 {
+    if input.len() > DEFAULT_MAX_INPUT {
         return Err(HDLCError::TooMuchData);
     }
 
@@ -246,7 +281,7 @@ pub fn decode(input: &[u8], s_chars: SpecialChars) -> Result<ArrayVec<[u8; 1024]
         return Err(HDLCError::MissingFinalFend);
     }
 
-    let mut output = ArrayVec::<[u8; 1024]>::new();
+    let mut output = ArrayVec::<[u8; DEFAULT_FRAME]>::new();
 
     // Iterator over the input that allows peeking
     let mut input_iter = input[1..input.len() - 1].iter().peekable();
@@ -271,13 +306,521 @@ pub fn decode(input: &[u8], s_chars: SpecialChars) -> Result<ArrayVec<[u8; 1024]
         }
     }
 
-    if output.len() > 260 {
+    if output.len() > DEFAULT_MAX_PAYLOAD {
         return Err(HDLCError::TooMuchDecodedData);
     }
 
     Ok(output)
 }
 
+/// Writes `byte` to `out[*len]` and advances `*len`, or reports that `out` is full.
+fn push_checked(out: &mut [u8], len: &mut usize, byte: u8) -> Result<(), HDLCError> {
+    if *len >= out.len() {
+        return Err(HDLCError::BufferTooSmall);
+    }
+    out[*len] = byte;
+    *len += 1;
+    Ok(())
+}
+
+/// Produces escaped (encoded) message surrounded with `FEND`, writing directly into a
+/// caller-provided buffer instead of allocating an `ArrayVec`.
+///
+/// # Inputs
+/// * **&[u8]**: The bytes you want to encode
+/// * **SpecialChars**: The special characters you want to swap
+/// * **&mut [u8]**: The buffer to stuff the encoded message into
+///
+/// # Output
+///
+/// * **Result<usize>**: Number of bytes written into `out`
+///
+/// # Error
+///
+/// * **HDLCError::TooMuchData**: More than `DEFAULT_MAX_PAYLOAD` bytes to be encoded
+/// * **HDLCError::BufferTooSmall**: `out` is not large enough to hold the encoded message
+///
+/// # Example
+/// ```rust
+/// extern crate sensirion_hdlc;
+/// let chars = sensirion_hdlc::SpecialChars::default();
+/// let input = [0x01, 0x50, 0x00, 0x00, 0x00, 0x05, 0x80, 0x09];
+/// let mut out = [0u8; 16];
+/// let len = sensirion_hdlc::encode_slice(&input, chars, &mut out).unwrap();
+/// ```
+pub fn encode_slice(
+    data: &[u8],
+    s_chars: SpecialChars,
+    out: &mut [u8],
+) -> Result<usize, HDLCError> {
+    if data.len() > DEFAULT_MAX_PAYLOAD {
+        return Err(HDLCError::TooMuchData);
+    }
+
+    let mut len = 0usize;
+    push_checked(out, &mut len, s_chars.fend)?;
+
+    for value in data.iter() {
+        match *value {
+            // FEND , FESC, ob1 and ob2
+            val if val == s_chars.fesc => {
+                push_checked(out, &mut len, s_chars.fesc)?;
+                push_checked(out, &mut len, s_chars.tfesc)?;
+            }
+            val if val == s_chars.fend => {
+                push_checked(out, &mut len, s_chars.fesc)?;
+                push_checked(out, &mut len, s_chars.tfend)?;
+            }
+            val if val == s_chars.ob1 => {
+                push_checked(out, &mut len, s_chars.fesc)?;
+                push_checked(out, &mut len, s_chars.tfob1)?;
+            }
+            val if val == s_chars.ob2 => {
+                push_checked(out, &mut len, s_chars.fesc)?;
+                push_checked(out, &mut len, s_chars.tfob2)?;
+            }
+            // Handle any other bytes
+            _ => push_checked(out, &mut len, *value)?,
+        }
+    }
+
+    push_checked(out, &mut len, s_chars.fend)?;
+
+    Ok(len)
+}
+
+/// Produces unescaped (decoded) message without `FEND` characters, writing directly into
+/// a caller-provided buffer instead of allocating an `ArrayVec`.
+///
+/// # Inputs
+/// * **&[u8]**: The bytes you want to decode
+/// * **SpecialChars**: The special characters you want to swap
+/// * **&mut [u8]**: The buffer to de-stuff the decoded message into
+///
+/// # Output
+///
+/// * **Result<usize>**: Number of bytes written into `out`
+///
+/// # Error
+///
+/// * **HDLCError::FendCharInData**: Found the `SpecialChars::fend` inside the message
+/// * **HDLCError::MissingTradeChar**: An `fesc` byte was not followed by a `tfend`,
+/// `tfesc`, `tfob1` or `tfob2`
+/// * **HDLCError::MissingFirstFend**: Input slice is missing a first `SpecialChars::fend`
+/// * **HDLCError::MissingFinalFend**: Input slice is missing a final `SpecialChars::fend`
+/// * **HDLCError::TooFewData**: Data to decode is fewer than 4 bytes
+/// * **HDLCError::BufferTooSmall**: `out` is not large enough to hold the decoded message
+///
+/// # Example
+/// ```rust
+/// extern crate sensirion_hdlc;
+/// let chars = sensirion_hdlc::SpecialChars::default();
+/// let input = [chars.fend, 0x01, 0x50, 0x00, 0x00, 0x00, 0x05, 0x80, 0x09, chars.fend];
+/// let mut out = [0u8; 16];
+/// let len = sensirion_hdlc::decode_slice(&input, chars, &mut out).unwrap();
+/// ```
+pub fn decode_slice(
+    input: &[u8],
+    s_chars: SpecialChars,
+    out: &mut [u8],
+) -> Result<usize, HDLCError> {
+    if input.len() < 4 {
+        return Err(HDLCError::TooFewData);
+    }
+
+    // Verify input begins with a FEND
+    if input[0] != s_chars.fend {
+        return Err(HDLCError::MissingFirstFend);
+    }
+    // Verify input ends with a FEND
+    if input[input.len() - 1] != s_chars.fend {
+        return Err(HDLCError::MissingFinalFend);
+    }
+
+    let mut len = 0usize;
+
+    // Iterator over the input that allows peeking
+    let mut input_iter = input[1..input.len() - 1].iter().peekable();
+
+    // Loop over every byte of the message
+    while let Some(value) = input_iter.next() {
+        match *value {
+            // Handle a FESC
+            val if val == s_chars.fesc => match input_iter.next() {
+                Some(&val) if val == s_chars.tfend => push_checked(out, &mut len, s_chars.fend)?,
+                Some(&val) if val == s_chars.tfesc => push_checked(out, &mut len, s_chars.fesc)?,
+                Some(&val) if val == s_chars.tfob1 => push_checked(out, &mut len, s_chars.ob1)?,
+                Some(&val) if val == s_chars.tfob2 => push_checked(out, &mut len, s_chars.ob2)?,
+                _ => return Err(HDLCError::MissingTradeChar),
+            },
+            // Handle a FEND
+            val if val == s_chars.fend => {
+                return Err(HDLCError::FendCharInData);
+            }
+            // Handle any other bytes
+            _ => push_checked(out, &mut len, *value)?,
+        }
+    }
+
+    Ok(len)
+}
+
+/// Computes the SHDLC checksum of `data`: the least-significant byte of the wrapping
+/// sum of all bytes, complemented.
+fn checksum(data: &[u8]) -> u8 {
+    let sum = data.iter().fold(0u8, |acc, &byte| acc.wrapping_add(byte));
+    !sum
+}
+
+/// Appends the SHDLC checksum byte to `data`, then produces an escaped (encoded) message
+/// surrounded with `FEND`, just like [`encode`].
+///
+/// # Inputs
+/// * **&[u8]**: The bytes you want to encode, without a checksum
+/// * **SpecialChars**: The special characters you want to swap
+///
+/// # Output
+///
+/// * **Result<ArrayVec<u8;DEFAULT_FRAME>>**: Encoded output message, with the checksum
+/// byte included before stuffing
+///
+/// # Error
+///
+/// * **HDLCError::TooMuchData**: More than `DEFAULT_MAX_PAYLOAD - 1` bytes to be encoded
+///
+/// # Example
+/// ```rust
+/// extern crate sensirion_hdlc;
+/// let chars = sensirion_hdlc::SpecialChars::default();
+/// let input: Vec<u8> = vec![0x00, 0x00, 0x02, 0x01, 0x03];
+/// let op_vec = sensirion_hdlc::encode_with_checksum(&input.to_vec(), chars);
+/// ```
+pub fn encode_with_checksum(
+    data: &[u8],
+    s_chars: SpecialChars,
+) -> Result<ArrayVec<[u8; DEFAULT_FRAME]>, HDLCError> {
+    if data.len() > DEFAULT_MAX_PAYLOAD - 1 {
+        return Err(HDLCError::TooMuchData);
+    }
+
+    let mut buffer = ArrayVec::<[u8; DEFAULT_MAX_PAYLOAD]>::new();
+    for value in data.iter() {
+        buffer.push(*value);
+    }
+    buffer.push(checksum(data));
+
+    encode(&buffer, s_chars)
+}
+
+/// Decodes a message produced by [`encode_with_checksum`]: de-stuffs the frame, splits
+/// off the trailing checksum byte, and verifies it against the remaining payload.
+///
+/// # Inputs
+/// * **&[u8]**: The stuffed, checksummed message you want to decode
+/// * **SpecialChars**: The special characters you want to swap
+///
+/// # Output
+///
+/// * **Result<ArrayVec<u8;DEFAULT_MAX_PAYLOAD>>**: Decoded payload, with the checksum byte
+/// removed
+///
+/// # Error
+///
+/// * **HDLCError::InvalidChecksum**: The trailing checksum byte does not match the
+/// recomputed checksum of the remaining payload
+/// * **HDLCError::TooFewData**: Decoded payload is too short to contain a checksum byte
+///
+/// See [`decode`] for the other errors this can return.
+///
+/// # Example
+/// ```rust
+/// extern crate sensirion_hdlc;
+/// let chars = sensirion_hdlc::SpecialChars::default();
+/// let input = [0x7E, 0x00, 0x00, 0x02, 0x01, 0x03, 0xf9, 0x7E];
+/// let op_vec = sensirion_hdlc::decode_with_checksum(&input.to_vec(), chars);
+/// ```
+pub fn decode_with_checksum(
+    input: &[u8],
+    s_chars: SpecialChars,
+) -> Result<ArrayVec<[u8; DEFAULT_MAX_PAYLOAD]>, HDLCError> {
+    let decoded = decode(input, s_chars)?;
+
+    if decoded.is_empty() {
+        return Err(HDLCError::TooFewData);
+    }
+
+    let (payload, received) = decoded.split_at(decoded.len() - 1);
+    let expected = checksum(payload);
+
+    if received[0] != expected {
+        return Err(HDLCError::InvalidChecksum);
+    }
+
+    let mut output = ArrayVec::<[u8; DEFAULT_MAX_PAYLOAD]>::new();
+    for value in payload.iter() {
+        output.push(*value);
+    }
+
+    Ok(output)
+}
+
+/// MOSI (host to device) SHDLC frame builder.
+///
+/// Assembles the `[address, command, length, data.., checksum]` byte layout a Sensirion
+/// device expects, so callers hand over `address`/`command`/`data` instead of
+/// hand-constructing that layout themselves.
+///
+/// # Example
+/// ```rust
+/// extern crate sensirion_hdlc;
+/// use sensirion_hdlc::{MosiFrame, SpecialChars};
+///
+/// let frame = MosiFrame {
+///     address: 0x00,
+///     command: 0x00,
+///     data: &[0x01, 0x03],
+/// };
+/// let encoded = frame.to_frame(SpecialChars::default()).unwrap();
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct MosiFrame<'a> {
+    /// Device address to send the frame to.
+    pub address: u8,
+    /// Command byte.
+    pub command: u8,
+    /// Data bytes to send.
+    pub data: &'a [u8],
+}
+
+impl<'a> MosiFrame<'a> {
+    /// Emits the length field, appends the checksum, byte-stuffs the result and wraps
+    /// it in `fend`.
+    ///
+    /// # Error
+    ///
+    /// * **HDLCError::TooMuchData**: `data` is longer than `u8::MAX`, so it cannot fit
+    /// the single-byte length field, or it is too long to fit the `[address, command,
+    /// length, data.., checksum]` layout within `DEFAULT_MAX_PAYLOAD` bytes
+    pub fn to_frame(
+        &self,
+        s_chars: SpecialChars,
+    ) -> Result<ArrayVec<[u8; DEFAULT_FRAME]>, HDLCError> {
+        if self.data.len() > u8::MAX as usize || self.data.len() > DEFAULT_MAX_PAYLOAD - 3 {
+            return Err(HDLCError::TooMuchData);
+        }
+
+        let mut buffer = ArrayVec::<[u8; DEFAULT_MAX_PAYLOAD]>::new();
+        buffer.push(self.address);
+        buffer.push(self.command);
+        buffer.push(self.data.len() as u8);
+        for value in self.data.iter() {
+            buffer.push(*value);
+        }
+
+        encode_with_checksum(&buffer, s_chars)
+    }
+}
+
+/// MISO (device to host) SHDLC frame parser.
+///
+/// Decomposes a received `[address, command, state, length, data.., checksum]` frame
+/// into its structured fields, rather than leaving the caller to pick the layout apart
+/// from a raw byte slice.
+///
+/// # Example
+/// ```rust
+/// extern crate sensirion_hdlc;
+/// use sensirion_hdlc::{MisoFrame, SpecialChars};
+///
+/// let chars = SpecialChars::default();
+/// let input = [chars.fend, 0x00, 0x00, 0x00, 0x02, 0x01, 0x03, 0xf9, chars.fend];
+/// let frame = MisoFrame::from_frame(&input, chars).unwrap();
+///
+/// assert_eq!(frame.data(), &[0x01, 0x03]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MisoFrame {
+    /// Device address the frame was received from.
+    pub address: u8,
+    /// Command byte being answered.
+    pub command: u8,
+    /// Device state/error byte.
+    pub state: u8,
+    /// Number of valid data bytes in the frame.
+    pub length: u8,
+    data: ArrayVec<[u8; DEFAULT_MAX_PAYLOAD]>,
+}
+
+impl MisoFrame {
+    /// De-stuffs `input`, verifies its checksum, and splits it into its structured
+    /// fields.
+    ///
+    /// # Error
+    ///
+    /// * **HDLCError::TooFewData**: Decoded frame is shorter than the `[address,
+    /// command, state, length]` header
+    /// * **HDLCError::TooMuchDecodedData**: `length` does not match the number of data
+    /// bytes actually present
+    ///
+    /// See [`decode_with_checksum`] for the other errors this can return.
+    pub fn from_frame(input: &[u8], s_chars: SpecialChars) -> Result<MisoFrame, HDLCError> {
+        let decoded = decode_with_checksum(input, s_chars)?;
+
+        if decoded.len() < 4 {
+            return Err(HDLCError::TooFewData);
+        }
+
+        let address = decoded[0];
+        let command = decoded[1];
+        let state = decoded[2];
+        let length = decoded[3];
+
+        if decoded.len() - 4 != length as usize {
+            return Err(HDLCError::TooMuchDecodedData);
+        }
+
+        let mut data = ArrayVec::<[u8; DEFAULT_MAX_PAYLOAD]>::new();
+        for value in decoded[4..].iter() {
+            data.push(*value);
+        }
+
+        Ok(MisoFrame {
+            address,
+            command,
+            state,
+            length,
+            data,
+        })
+    }
+
+    /// The validated data bytes carried by the frame.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// Internal state of the [`FrameDecoder`] byte-at-a-time state machine.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum DecoderState {
+    /// Discarding bytes until the opening `fend` is seen.
+    Idle,
+    /// Accumulating de-stuffed bytes inside a frame.
+    InFrame,
+    /// The previous byte was `fesc`; the next byte must map back to the original.
+    AfterEsc,
+}
+
+/// Stateful, byte-at-a-time SHDLC decoder for streaming sources such as a UART.
+///
+/// Unlike [`decode`], which requires a complete, already-buffered frame, `FrameDecoder`
+/// is fed one byte at a time (for example from an interrupt or DMA callback) and only
+/// reports a decoded frame once a closing `fend` has been seen. This lets a caller decode
+/// directly out of the byte stream as it arrives, without knowing the frame boundaries in
+/// advance.
+///
+/// # Example
+/// ```rust
+/// extern crate sensirion_hdlc;
+/// use sensirion_hdlc::{FrameDecoder, SpecialChars};
+///
+/// let chars = SpecialChars::default();
+/// let mut decoder = FrameDecoder::new(chars);
+/// let msg = [chars.fend, 0x01, 0x50, chars.fend];
+///
+/// let mut result = None;
+/// for byte in msg.iter() {
+///     result = decoder.push(*byte);
+/// }
+///
+/// assert_eq!(result.unwrap().unwrap()[0..2], [0x01, 0x50]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameDecoder {
+    s_chars: SpecialChars,
+    state: DecoderState,
+    buffer: ArrayVec<[u8; DEFAULT_FRAME]>,
+}
+
+impl FrameDecoder {
+    /// Creates a new `FrameDecoder` using the given special characters.
+    pub fn new(s_chars: SpecialChars) -> FrameDecoder {
+        FrameDecoder {
+            s_chars,
+            state: DecoderState::Idle,
+            buffer: ArrayVec::new(),
+        }
+    }
+
+    /// Feeds a single received byte into the decoder.
+    ///
+    /// # Output
+    ///
+    /// * **None**: The frame is still being assembled; keep feeding bytes.
+    /// * **Some(Ok(...))**: A closing `fend` was seen and the decoded payload is returned.
+    /// * **Some(Err(...))**: The frame was malformed.
+    ///
+    /// Either way, once `push` returns `Some(...)` the decoder has reset back to `Idle`
+    /// and is ready to assemble the next frame.
+    ///
+    /// # Error
+    ///
+    /// * **HDLCError::MissingTradeChar**: An `fesc` byte was followed by something other
+    /// than `tfend`, `tfesc`, `tfob1` or `tfob2` (including a stray `fend`).
+    pub fn push(&mut self, byte: u8) -> Option<Result<ArrayVec<[u8; DEFAULT_FRAME]>, HDLCError>> {
+        match self.state {
+            DecoderState::Idle => {
+                if byte == self.s_chars.fend {
+                    self.buffer.clear();
+                    self.state = DecoderState::InFrame;
+                }
+                None
+            }
+            DecoderState::InFrame => match byte {
+                val if val == self.s_chars.fend => {
+                    self.state = DecoderState::Idle;
+                    Some(Ok(core::mem::replace(&mut self.buffer, ArrayVec::new())))
+                }
+                val if val == self.s_chars.fesc => {
+                    self.state = DecoderState::AfterEsc;
+                    None
+                }
+                _ => self.push_decoded_byte(byte),
+            },
+            DecoderState::AfterEsc => {
+                self.state = DecoderState::InFrame;
+                match byte {
+                    val if val == self.s_chars.tfend => self.push_decoded_byte(self.s_chars.fend),
+                    val if val == self.s_chars.tfesc => self.push_decoded_byte(self.s_chars.fesc),
+                    val if val == self.s_chars.tfob1 => self.push_decoded_byte(self.s_chars.ob1),
+                    val if val == self.s_chars.tfob2 => self.push_decoded_byte(self.s_chars.ob2),
+                    _ => {
+                        self.state = DecoderState::Idle;
+                        Some(Err(HDLCError::MissingTradeChar))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Appends a de-stuffed byte to `self.buffer`, resetting to `Idle` and reporting
+    /// `TooMuchData` instead of overflowing the buffer if it's already full.
+    ///
+    /// This guards against an untrusted, unbounded streaming source (a line glitch or a
+    /// device that never emits a closing `fend`) feeding more in-frame bytes than the
+    /// buffer can hold, which would otherwise panic on the underlying `ArrayVec::push`.
+    fn push_decoded_byte(
+        &mut self,
+        byte: u8,
+    ) -> Option<Result<ArrayVec<[u8; DEFAULT_FRAME]>, HDLCError>> {
+        if self.buffer.len() >= self.buffer.capacity() {
+            self.state = DecoderState::Idle;
+            return Some(Err(HDLCError::TooMuchData));
+        }
+        self.buffer.push(byte);
+        None
+    }
+}
+
 #[derive(Debug, PartialEq)]
 /// Common error for HDLC actions.
 pub enum HDLCError {
@@ -299,6 +842,8 @@ pub enum HDLCError {
     InvalidChecksum,
     /// More than 259 bytes resulted after decoding SHDLC frame
     TooMuchDecodedData,
+    /// The caller-provided output buffer is too small to hold the result
+    BufferTooSmall,
 }
 
 #[cfg(test)]
@@ -328,4 +873,171 @@ mod tests {
         let encoded = decode(&mosi_data, SpecialChars::default()).unwrap();
         assert_eq!(encoded[0..encoded.len()], expected);
     }
+
+    #[test]
+    fn frame_decoder_discards_leading_noise() {
+        let s_chars = SpecialChars::default();
+        let mut decoder = FrameDecoder::new(s_chars);
+        let expected = [0x00, 0x01, 0x00, 0xfe];
+        let stream = [0xff, 0xff, 0x7e, 0x00, 0x01, 0x00, 0xfe, 0x7e];
+
+        let mut result = None;
+        for byte in stream.iter() {
+            result = decoder.push(*byte);
+        }
+
+        let decoded = result.unwrap().unwrap();
+        assert_eq!(decoded[0..decoded.len()], expected);
+    }
+
+    #[test]
+    fn frame_decoder_destuffs_escaped_bytes() {
+        let s_chars = SpecialChars::default();
+        let mut decoder = FrameDecoder::new(s_chars);
+        let expected = [0x01, 0x7E, 0x70];
+        let stream = [0x7e, 0x01, 0x7d, 0x5e, 0x70, 0x7e];
+
+        let mut result = None;
+        for byte in stream.iter() {
+            result = decoder.push(*byte);
+        }
+
+        let decoded = result.unwrap().unwrap();
+        assert_eq!(decoded[0..decoded.len()], expected);
+    }
+
+    #[test]
+    fn frame_decoder_errors_on_frame_too_long() {
+        let s_chars = SpecialChars::default();
+        let mut decoder = FrameDecoder::new(s_chars);
+
+        let mut result = decoder.push(s_chars.fend);
+        assert_eq!(result, None);
+
+        for _ in 0..=DEFAULT_FRAME {
+            result = decoder.push(0x00);
+        }
+
+        assert_eq!(result, Some(Err(HDLCError::TooMuchData)));
+    }
+
+    #[test]
+    fn frame_decoder_errors_on_missing_trade_char() {
+        let s_chars = SpecialChars::default();
+        let mut decoder = FrameDecoder::new(s_chars);
+        let stream = [0x7e, 0x01, 0x7d, 0x00];
+
+        let mut result = None;
+        for byte in stream.iter() {
+            result = decoder.push(*byte);
+        }
+
+        assert_eq!(result, Some(Err(HDLCError::MissingTradeChar)));
+    }
+
+    #[test]
+    fn encode_with_checksum_appends_checksum_byte() {
+        let mosi_data = [0x00, 0x00, 0x02, 0x01, 0x03];
+        let expected = [0x7e, 0x00, 0x00, 0x02, 0x01, 0x03, 0xf9, 0x7e];
+        let encoded = encode_with_checksum(&mosi_data, SpecialChars::default()).unwrap();
+        assert_eq!(encoded[0..encoded.len()], expected);
+    }
+
+    #[test]
+    fn decode_with_checksum_strips_valid_checksum() {
+        let expected = [0x00, 0x00, 0x02, 0x01, 0x03];
+        let mosi_data = [0x7e, 0x00, 0x00, 0x02, 0x01, 0x03, 0xf9, 0x7e];
+        let decoded = decode_with_checksum(&mosi_data, SpecialChars::default()).unwrap();
+        assert_eq!(decoded[0..decoded.len()], expected);
+    }
+
+    #[test]
+    fn decode_with_checksum_rejects_invalid_checksum() {
+        let mosi_data = [0x7e, 0x00, 0x00, 0x02, 0x01, 0x03, 0x00, 0x7e];
+        let result = decode_with_checksum(&mosi_data, SpecialChars::default());
+        assert_eq!(result, Err(HDLCError::InvalidChecksum));
+    }
+
+    #[test]
+    fn mosi_frame_to_frame_builds_full_layout() {
+        let frame = MosiFrame {
+            address: 0x00,
+            command: 0x00,
+            data: &[0x01, 0x03],
+        };
+        let expected = [0x7e, 0x00, 0x00, 0x02, 0x01, 0x03, 0xf9, 0x7e];
+        let encoded = frame.to_frame(SpecialChars::default()).unwrap();
+        assert_eq!(encoded[0..encoded.len()], expected);
+    }
+
+    #[test]
+    fn mosi_frame_to_frame_rejects_data_longer_than_length_byte() {
+        let data = [0u8; 256];
+        let frame = MosiFrame {
+            address: 0x00,
+            command: 0x00,
+            data: &data,
+        };
+        let result = frame.to_frame(SpecialChars::default());
+        assert_eq!(result, Err(HDLCError::TooMuchData));
+    }
+
+    #[test]
+    fn miso_frame_from_frame_parses_fields() {
+        let chars = SpecialChars::default();
+        let input = [
+            chars.fend, 0x00, 0x00, 0x00, 0x02, 0x01, 0x03, 0xf9, chars.fend,
+        ];
+        let frame = MisoFrame::from_frame(&input, chars).unwrap();
+
+        assert_eq!(frame.address, 0x00);
+        assert_eq!(frame.command, 0x00);
+        assert_eq!(frame.state, 0x00);
+        assert_eq!(frame.length, 0x02);
+        assert_eq!(frame.data(), &[0x01, 0x03]);
+    }
+
+    #[test]
+    fn miso_frame_from_frame_rejects_length_mismatch() {
+        let chars = SpecialChars::default();
+        let input = [
+            chars.fend, 0x00, 0x00, 0x00, 0x05, 0x01, 0x03, 0xf6, chars.fend,
+        ];
+        let result = MisoFrame::from_frame(&input, chars);
+        assert_eq!(result, Err(HDLCError::TooMuchDecodedData));
+    }
+
+    #[test]
+    fn encode_slice_writes_into_buffer() {
+        let mosi_data = [0x00, 0x01, 0x00, 0xfe];
+        let expected = [0x7e, 0x00, 0x01, 0x00, 0xfe, 0x7e];
+        let mut out = [0u8; 16];
+        let len = encode_slice(&mosi_data, SpecialChars::default(), &mut out).unwrap();
+        assert_eq!(&out[0..len], expected);
+    }
+
+    #[test]
+    fn encode_slice_reports_buffer_too_small() {
+        let mosi_data = [0x00, 0x01, 0x00, 0xfe];
+        let mut out = [0u8; 4];
+        let result = encode_slice(&mosi_data, SpecialChars::default(), &mut out);
+        assert_eq!(result, Err(HDLCError::BufferTooSmall));
+    }
+
+    #[test]
+    fn decode_slice_writes_into_buffer() {
+        let expected = [0x00, 0x01, 0x00, 0xfe];
+        let mosi_data = [0x7e, 0x00, 0x01, 0x00, 0xfe, 0x7e];
+        let mut out = [0u8; 16];
+        let len = decode_slice(&mosi_data, SpecialChars::default(), &mut out).unwrap();
+        assert_eq!(&out[0..len], expected);
+    }
+
+    #[test]
+    fn decode_slice_reports_buffer_too_small() {
+        let mosi_data = [0x7e, 0x00, 0x01, 0x00, 0xfe, 0x7e];
+        let mut out = [0u8; 2];
+        let result = decode_slice(&mosi_data, SpecialChars::default(), &mut out);
+        assert_eq!(result, Err(HDLCError::BufferTooSmall));
+    }
 }